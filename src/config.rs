@@ -0,0 +1,151 @@
+use crate::CookieMode;
+use std::fmt;
+use time::Duration;
+
+/// Controls when a session is actually written to the database.
+///
+/// Without a policy every request carrying a `client` would persist a row,
+/// even for anonymous visitors who never put anything into their session.
+/// Choosing `ChangedOnly` or `ExistingOnly` cuts that write amplification down
+/// considerably for crawler and anonymous traffic.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum PersistencePolicy {
+    /// Always persist the session, matching the crate's historical behavior.
+    #[default]
+    Always,
+    /// Only persist the session if its in-memory [`SessionData`](crate::SessionData)
+    /// was mutated since it was loaded.
+    ChangedOnly,
+    /// Only persist the session if it was already loaded from the store, i.e. an
+    /// inbound cookie matched an existing row. A session created fresh is kept
+    /// in memory only and is never written under this policy.
+    ExistingOnly,
+}
+
+/// Configuration used to construct a [`SessionStore`](crate::SessionStore).
+#[derive(Clone)]
+pub struct SessionConfig {
+    /// Name of the database table sessions are persisted under.
+    pub table_name: String,
+    /// Name of the cookie used to carry the session id to the browser.
+    pub cookie_name: String,
+    /// How long an idle session is kept in memory before being evicted.
+    pub memory_lifespan: Duration,
+    /// How long a persisted session is kept in the database before expiring.
+    pub lifespan: Duration,
+    /// Controls when [`SessionStore::store_session`](crate::SessionStore) actually
+    /// writes to the database. Defaults to [`PersistencePolicy::Always`].
+    pub persistence_policy: PersistencePolicy,
+    /// Controls whether the session id cookie is sent as-is, signed, or
+    /// encrypted. Defaults to [`CookieMode::Plain`].
+    pub cookie_mode: CookieMode,
+    /// The key used to sign or encrypt the session id cookie. Required when
+    /// `cookie_mode` is [`CookieMode::Signed`] or [`CookieMode::Private`];
+    /// set it with [`SessionConfig::with_key`].
+    #[cfg(any(feature = "signed", feature = "private"))]
+    pub key: Option<cookie::Key>,
+}
+
+impl fmt::Debug for SessionConfig {
+    /// Hand-written so the key material behind
+    /// [`SessionConfig::with_key`] never ends up in a log or error message —
+    /// `cookie::Key` deliberately does not implement `Debug`, so a derived
+    /// impl would fail to compile as soon as the `signed`/`private` feature
+    /// is enabled.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut debug = f.debug_struct("SessionConfig");
+        debug
+            .field("table_name", &self.table_name)
+            .field("cookie_name", &self.cookie_name)
+            .field("memory_lifespan", &self.memory_lifespan)
+            .field("lifespan", &self.lifespan)
+            .field("persistence_policy", &self.persistence_policy)
+            .field("cookie_mode", &self.cookie_mode);
+
+        #[cfg(any(feature = "signed", feature = "private"))]
+        debug.field("key", &self.key.as_ref().map(|_| "Key { .. }"));
+
+        debug.finish()
+    }
+}
+
+impl Default for SessionConfig {
+    fn default() -> Self {
+        Self {
+            table_name: "sessions".into(),
+            cookie_name: "session_id".into(),
+            memory_lifespan: Duration::hours(6),
+            lifespan: Duration::hours(6),
+            persistence_policy: PersistencePolicy::default(),
+            cookie_mode: CookieMode::default(),
+            #[cfg(any(feature = "signed", feature = "private"))]
+            key: None,
+        }
+    }
+}
+
+impl SessionConfig {
+    /// Sets the [`PersistencePolicy`] used to decide whether a session gets
+    /// written to the database.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use axum_session::{SessionConfig, PersistencePolicy};
+    ///
+    /// let config = SessionConfig::default().with_persistence_policy(PersistencePolicy::ChangedOnly);
+    /// ```
+    ///
+    #[inline]
+    pub fn with_persistence_policy(mut self, policy: PersistencePolicy) -> Self {
+        self.persistence_policy = policy;
+        self
+    }
+
+    /// Sets the [`CookieMode`] used to protect the session id cookie.
+    ///
+    /// `CookieMode::Signed` and `CookieMode::Private` only exist when the
+    /// crate's matching `signed`/`private` cargo feature is enabled, so
+    /// selecting a mode whose feature isn't on is a compile error rather than
+    /// a silent fallback to plain cookies.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # #[cfg(feature = "signed")]
+    /// # {
+    /// use axum_session::{SessionConfig, CookieMode};
+    ///
+    /// let config = SessionConfig::default().with_cookie_mode(CookieMode::Signed);
+    /// # }
+    /// ```
+    ///
+    #[inline]
+    pub fn with_cookie_mode(mut self, mode: CookieMode) -> Self {
+        self.cookie_mode = mode;
+        self
+    }
+
+    /// Sets the key used to sign or encrypt the session id cookie.
+    ///
+    /// Required when `cookie_mode` is [`CookieMode::Signed`] or
+    /// [`CookieMode::Private`]; ignored otherwise.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # #[cfg(feature = "signed")]
+    /// # {
+    /// use axum_session::{SessionConfig, CookieMode};
+    /// use cookie::Key;
+    ///
+    /// let config = SessionConfig::default()
+    ///     .with_cookie_mode(CookieMode::Signed)
+    ///     .with_key(Key::generate());
+    /// # }
+    /// ```
+    ///
+    #[cfg(any(feature = "signed", feature = "private"))]
+    #[inline]
+    pub fn with_key(mut self, key: cookie::Key) -> Self {
+        self.key = Some(key);
+        self
+    }
+}
@@ -0,0 +1,207 @@
+use crate::SessionConfig;
+use cookie::{Cookie, CookieJar};
+use percent_encoding::{percent_decode_str, utf8_percent_encode, NON_ALPHANUMERIC};
+
+/// Selects how the session id cookie's value is protected in transit.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CookieMode {
+    /// The session id is neither signed nor encrypted; like every mode, the
+    /// value is percent-encoded, so a UUID's `-` is sent as `%2D`.
+    #[default]
+    Plain,
+    /// The cookie value is HMAC-signed, so a tampered value is rejected, but
+    /// the session id itself remains readable on the wire. Requires the
+    /// `signed` cargo feature and [`SessionConfig::with_key`].
+    #[cfg(feature = "signed")]
+    Signed,
+    /// The cookie value is AEAD-encrypted, so the session id is neither
+    /// readable nor forgeable without the configured key. Requires the
+    /// `private` cargo feature and [`SessionConfig::with_key`].
+    #[cfg(feature = "private")]
+    Private,
+}
+
+/// Builds the cookie value that should be sent to the client for `id`,
+/// applying the configured [`CookieMode`] and percent-encoding the result so
+/// a signed or encrypted payload survives transport intact.
+pub(crate) fn seal(config: &SessionConfig, id: &str) -> String {
+    let mut jar = CookieJar::new();
+    let plain = Cookie::new(config.cookie_name.clone(), id.to_string());
+
+    let value = match config.cookie_mode {
+        CookieMode::Plain => {
+            jar.add(plain);
+            jar.get(&config.cookie_name)
+                .expect("cookie was just added")
+                .value()
+                .to_string()
+        }
+        #[cfg(feature = "signed")]
+        CookieMode::Signed => {
+            let key = config
+                .key
+                .as_ref()
+                .expect("CookieMode::Signed requires SessionConfig::with_key");
+            jar.signed_mut(key).add(plain);
+            jar.get(&config.cookie_name)
+                .expect("cookie was just added")
+                .value()
+                .to_string()
+        }
+        #[cfg(feature = "private")]
+        CookieMode::Private => {
+            let key = config
+                .key
+                .as_ref()
+                .expect("CookieMode::Private requires SessionConfig::with_key");
+            jar.private_mut(key).add(plain);
+            jar.get(&config.cookie_name)
+                .expect("cookie was just added")
+                .value()
+                .to_string()
+        }
+    };
+
+    utf8_percent_encode(&value, NON_ALPHANUMERIC).to_string()
+}
+
+/// Recovers the session id from an inbound cookie value, applying the
+/// configured [`CookieMode`].
+///
+/// Returns `None` if the value is missing, malformed, or fails signature or
+/// decryption verification — a tampered or forged cookie must never resolve
+/// to an id, so callers must treat `None` the same as "no cookie sent" and
+/// skip the database lookup entirely.
+pub(crate) fn unseal(config: &SessionConfig, raw_value: &str) -> Option<String> {
+    let decoded = percent_decode_str(raw_value)
+        .decode_utf8()
+        .ok()?
+        .into_owned();
+
+    let mut jar = CookieJar::new();
+    jar.add_original(Cookie::new(config.cookie_name.clone(), decoded));
+
+    match config.cookie_mode {
+        CookieMode::Plain => jar
+            .get(&config.cookie_name)
+            .map(|cookie| cookie.value().to_string()),
+        #[cfg(feature = "signed")]
+        CookieMode::Signed => {
+            let key = config.key.as_ref()?;
+            jar.signed(key)
+                .get(&config.cookie_name)
+                .map(|cookie| cookie.value().to_string())
+        }
+        #[cfg(feature = "private")]
+        CookieMode::Private => {
+            let key = config.key.as_ref()?;
+            jar.private(key)
+                .get(&config.cookie_name)
+                .map(|cookie| cookie.value().to_string())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn plain_round_trips() {
+        let config = SessionConfig::default();
+        let sealed = seal(&config, "session-id");
+
+        assert_eq!(unseal(&config, &sealed).as_deref(), Some("session-id"));
+    }
+
+    #[test]
+    fn plain_percent_encodes_the_value() {
+        let mut config = SessionConfig::default();
+        config.cookie_name = "a b".to_string();
+        let sealed = seal(&config, "needs encoding");
+
+        assert!(!sealed.contains(' '));
+        assert_eq!(unseal(&config, &sealed).as_deref(), Some("needs encoding"));
+    }
+
+    #[cfg(feature = "signed")]
+    #[test]
+    fn signed_round_trips_with_the_right_key() {
+        let config = SessionConfig::default()
+            .with_cookie_mode(CookieMode::Signed)
+            .with_key(cookie::Key::generate());
+        let sealed = seal(&config, "session-id");
+
+        assert_eq!(unseal(&config, &sealed).as_deref(), Some("session-id"));
+    }
+
+    #[cfg(feature = "signed")]
+    #[test]
+    fn signed_rejects_a_tampered_value() {
+        let config = SessionConfig::default()
+            .with_cookie_mode(CookieMode::Signed)
+            .with_key(cookie::Key::generate());
+        let mut sealed = seal(&config, "session-id");
+        sealed.push('x');
+
+        assert_eq!(unseal(&config, &sealed), None);
+    }
+
+    #[cfg(feature = "signed")]
+    #[test]
+    fn signed_rejects_a_value_sealed_with_a_different_key() {
+        let sealer = SessionConfig::default()
+            .with_cookie_mode(CookieMode::Signed)
+            .with_key(cookie::Key::generate());
+        let sealed = seal(&sealer, "session-id");
+
+        let verifier = SessionConfig::default()
+            .with_cookie_mode(CookieMode::Signed)
+            .with_key(cookie::Key::generate());
+
+        assert_eq!(unseal(&verifier, &sealed), None);
+    }
+
+    #[cfg(feature = "signed")]
+    #[test]
+    #[should_panic(expected = "CookieMode::Signed requires SessionConfig::with_key")]
+    fn signed_without_a_key_panics_instead_of_shipping_plaintext() {
+        let config = SessionConfig::default().with_cookie_mode(CookieMode::Signed);
+
+        seal(&config, "session-id");
+    }
+
+    #[cfg(feature = "private")]
+    #[test]
+    fn private_round_trips_with_the_right_key() {
+        let config = SessionConfig::default()
+            .with_cookie_mode(CookieMode::Private)
+            .with_key(cookie::Key::generate());
+        let sealed = seal(&config, "session-id");
+
+        assert_eq!(unseal(&config, &sealed).as_deref(), Some("session-id"));
+    }
+
+    #[cfg(feature = "private")]
+    #[test]
+    fn private_rejects_a_tampered_value() {
+        let config = SessionConfig::default()
+            .with_cookie_mode(CookieMode::Private)
+            .with_key(cookie::Key::generate());
+        let mut sealed = seal(&config, "session-id");
+        sealed.push('x');
+
+        assert_eq!(unseal(&config, &sealed), None);
+    }
+
+    #[cfg(feature = "private")]
+    #[test]
+    fn private_cookie_does_not_leak_the_session_id() {
+        let config = SessionConfig::default()
+            .with_cookie_mode(CookieMode::Private)
+            .with_key(cookie::Key::generate());
+        let sealed = seal(&config, "super-secret-session-id");
+
+        assert!(!sealed.contains("super-secret-session-id"));
+    }
+}
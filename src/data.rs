@@ -0,0 +1,172 @@
+use crate::SessionConfig;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::collections::HashMap;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+/// In-memory representation of a single session's state.
+///
+/// Generic over the payload type `D` so an application can keep one cohesive,
+/// strongly-typed session struct instead of paying per-key JSON
+/// (de)serialization on every field access. Defaults to
+/// `HashMap<String, String>`, which preserves the crate's original key/value
+/// API (see the `impl SessionData<HashMap<String, String>>` block below) for
+/// apps that don't opt into a typed payload.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+#[serde(bound = "D: Serialize + DeserializeOwned")]
+pub struct SessionData<D = HashMap<String, String>>
+where
+    D: Default + Serialize + DeserializeOwned + Send + Sync,
+{
+    pub(crate) id: Uuid,
+    pub(crate) data: D,
+    #[serde(with = "time::serde::rfc3339")]
+    pub(crate) expires: OffsetDateTime,
+    pub(crate) longterm: bool,
+    pub(crate) storable: bool,
+    #[serde(skip, default = "OffsetDateTime::now_utc")]
+    pub(crate) autoremove: OffsetDateTime,
+    #[serde(skip)]
+    pub(crate) destroy: bool,
+    #[serde(skip)]
+    pub(crate) renew: bool,
+    /// Set whenever the session was loaded from the database rather than created fresh.
+    /// Consulted by [`PersistencePolicy::ExistingOnly`](crate::PersistencePolicy::ExistingOnly).
+    #[serde(skip)]
+    pub(crate) loaded_from_store: bool,
+    /// Set whenever anything about this session is mutated after load/creation.
+    /// Consulted by [`PersistencePolicy::ChangedOnly`](crate::PersistencePolicy::ChangedOnly).
+    #[serde(skip)]
+    pub(crate) dirty: bool,
+    /// Optional owner/attribute tag, e.g. a user id, written to an indexed
+    /// column by [`SessionStore::store_session`](crate::SessionStore) so
+    /// [`SessionStore::get_sessions`](crate::SessionStore) and
+    /// [`SessionStore::destroy_sessions_by`](crate::SessionStore) can filter
+    /// on it without deserializing every row.
+    pub(crate) tag: Option<String>,
+}
+
+impl<D> SessionData<D>
+where
+    D: Default + Serialize + DeserializeOwned + Send + Sync,
+{
+    /// Creates a new session for `id` with a default-initialized payload.
+    pub fn new(id: Uuid, storable: bool, config: &SessionConfig) -> Self {
+        let now = OffsetDateTime::now_utc();
+
+        Self {
+            id,
+            data: D::default(),
+            expires: now + config.lifespan,
+            longterm: false,
+            storable,
+            autoremove: now + config.memory_lifespan,
+            destroy: false,
+            renew: false,
+            loaded_from_store: false,
+            dirty: false,
+            tag: None,
+        }
+    }
+
+    pub(crate) fn validate(&self) -> bool {
+        self.autoremove > OffsetDateTime::now_utc()
+    }
+
+    pub(crate) fn renew(&mut self) {
+        self.renew = true;
+        self.dirty = true;
+    }
+
+    pub(crate) fn destroy(&mut self) {
+        self.destroy = true;
+        self.dirty = true;
+    }
+
+    pub(crate) fn set_longterm(&mut self, longterm: bool) {
+        self.longterm = longterm;
+        self.dirty = true;
+    }
+
+    pub(crate) fn set_store(&mut self, storable: bool) {
+        self.storable = storable;
+        self.dirty = true;
+    }
+
+    /// Sets the owner/attribute tag used to filter this session in
+    /// [`SessionStore::get_sessions`](crate::SessionStore) and
+    /// [`SessionStore::destroy_sessions_by`](crate::SessionStore).
+    pub(crate) fn set_tag(&mut self, tag: Option<String>) {
+        self.tag = tag;
+        self.dirty = true;
+    }
+
+    /// Resets the session's payload back to `D::default()`.
+    ///
+    /// Only marks the session dirty if the payload actually changed, so
+    /// clearing an already-empty session doesn't trigger a write under
+    /// [`PersistencePolicy::ChangedOnly`](crate::PersistencePolicy::ChangedOnly).
+    pub(crate) fn clear(&mut self) {
+        let default = D::default();
+        let already_default = matches!(
+            (serde_json::to_string(&self.data), serde_json::to_string(&default)),
+            (Ok(current), Ok(default)) if current == default
+        );
+
+        self.data = default;
+
+        if !already_default {
+            self.dirty = true;
+        }
+    }
+
+    /// Grants `f` exclusive access to the session's typed payload under the
+    /// `DashMap` entry lock, marking the session dirty so
+    /// [`PersistencePolicy::ChangedOnly`](crate::PersistencePolicy::ChangedOnly)
+    /// persists the change.
+    pub(crate) fn with_data_mut<R>(&mut self, f: impl FnOnce(&mut D) -> R) -> R {
+        self.dirty = true;
+        f(&mut self.data)
+    }
+
+    pub(crate) fn data(&self) -> &D {
+        &self.data
+    }
+}
+
+/// The original key/value API, available whenever the session's payload is
+/// the default `HashMap<String, String>`.
+impl SessionData<HashMap<String, String>> {
+    pub(crate) fn get<N: DeserializeOwned>(&self, key: &str) -> Option<N> {
+        self.data
+            .get(key)
+            .and_then(|value| serde_json::from_str(value).ok())
+    }
+
+    pub(crate) fn get_remove<N: DeserializeOwned>(&mut self, key: &str) -> Option<N> {
+        let value = self
+            .data
+            .remove(key)
+            .and_then(|value| serde_json::from_str(&value).ok());
+
+        if value.is_some() {
+            self.dirty = true;
+        }
+
+        value
+    }
+
+    pub(crate) fn set(&mut self, key: &str, value: impl Serialize) {
+        if let Ok(value) = serde_json::to_string(&value) {
+            self.data.insert(key.to_string(), value);
+            self.dirty = true;
+        }
+    }
+
+    pub(crate) fn remove(&mut self, key: &str) {
+        if self.data.remove(key).is_some() {
+            self.dirty = true;
+        }
+    }
+}
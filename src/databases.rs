@@ -0,0 +1,150 @@
+use crate::SessionError;
+use async_trait::async_trait;
+use std::fmt::Debug;
+
+/// Provides the storage and query operations a [`SessionStore`](crate::SessionStore)
+/// needs from whichever database backend is backing it.
+#[async_trait]
+pub trait DatabasePool {
+    /// Creates the session table if it does not already exist.
+    async fn initiate(&self, table_name: &str) -> Result<(), SessionError>;
+
+    /// Deletes all sessions in `table_name` whose expiry has already passed.
+    async fn delete_by_expiry(&self, table_name: &str) -> Result<(), SessionError>;
+
+    /// Returns the number of sessions currently stored in `table_name`.
+    async fn count(&self, table_name: &str) -> Result<i64, SessionError>;
+
+    /// Loads the serialized session data stored for `id` in `table_name`, if any.
+    async fn load(&self, id: &str, table_name: &str) -> Result<Option<String>, SessionError>;
+
+    /// Upserts the serialized `session` for `id` into `table_name`, recording `expires`
+    /// so [`delete_by_expiry`](DatabasePool::delete_by_expiry) can later reap it, and
+    /// `tag` in an indexed column so [`get_sessions_by_tag`](DatabasePool::get_sessions_by_tag)
+    /// and [`delete_by_tag`](DatabasePool::delete_by_tag) can later filter on it.
+    async fn store(
+        &self,
+        id: &str,
+        session: &str,
+        expires: i64,
+        tag: Option<&str>,
+        table_name: &str,
+    ) -> Result<(), SessionError>;
+
+    /// Deletes the single session identified by `id` from `table_name`.
+    async fn delete_one_by_id(&self, id: &str, table_name: &str) -> Result<(), SessionError>;
+
+    /// Deletes every session stored in `table_name`.
+    async fn delete_all(&self, table_name: &str) -> Result<(), SessionError>;
+
+    /// Returns the serialized session data for every row in `table_name`
+    /// tagged with `tag`, e.g. every session belonging to a given user.
+    async fn get_sessions_by_tag(
+        &self,
+        tag: &str,
+        table_name: &str,
+    ) -> Result<Vec<String>, SessionError>;
+
+    /// Deletes every session in `table_name` tagged with `tag`. Used to
+    /// invalidate all of a user's sessions at once ("log out everywhere").
+    async fn delete_by_tag(&self, tag: &str, table_name: &str) -> Result<(), SessionError>;
+
+    /// Returns the schema version currently recorded for `table_name`, or `0`
+    /// if the tracking row does not exist yet, i.e. a brand new table.
+    async fn schema_version(&self, table_name: &str) -> Result<i64, SessionError>;
+
+    /// The ordered list of migrations this backend knows how to apply, e.g.
+    /// the index added to the expiry column consulted by
+    /// [`delete_by_expiry`](DatabasePool::delete_by_expiry).
+    fn migrations(&self) -> &'static [Migration];
+
+    /// Applies `migration` against `table_name` and records the new schema
+    /// version, both inside a single transaction.
+    async fn apply_migration(
+        &self,
+        table_name: &str,
+        migration: &Migration,
+    ) -> Result<(), SessionError>;
+}
+
+/// A single forward-only schema migration step.
+#[derive(Clone, Copy, Debug)]
+pub struct Migration {
+    /// Schema version this migration upgrades the table to.
+    pub version: i64,
+    /// Builds the SQL statement to run, given the configured session table name.
+    pub sql: fn(&str) -> String,
+}
+
+/// A [`DatabasePool`] implementation that performs no persistence.
+///
+/// Useful for tests and for applications that only need the in-memory
+/// session store without a backing database.
+#[derive(Clone, Debug)]
+pub struct SessionNullPool;
+
+#[async_trait]
+impl DatabasePool for SessionNullPool {
+    async fn initiate(&self, _table_name: &str) -> Result<(), SessionError> {
+        Ok(())
+    }
+
+    async fn delete_by_expiry(&self, _table_name: &str) -> Result<(), SessionError> {
+        Ok(())
+    }
+
+    async fn count(&self, _table_name: &str) -> Result<i64, SessionError> {
+        Ok(0)
+    }
+
+    async fn load(&self, _id: &str, _table_name: &str) -> Result<Option<String>, SessionError> {
+        Ok(None)
+    }
+
+    async fn store(
+        &self,
+        _id: &str,
+        _session: &str,
+        _expires: i64,
+        _tag: Option<&str>,
+        _table_name: &str,
+    ) -> Result<(), SessionError> {
+        Ok(())
+    }
+
+    async fn delete_one_by_id(&self, _id: &str, _table_name: &str) -> Result<(), SessionError> {
+        Ok(())
+    }
+
+    async fn delete_all(&self, _table_name: &str) -> Result<(), SessionError> {
+        Ok(())
+    }
+
+    async fn get_sessions_by_tag(
+        &self,
+        _tag: &str,
+        _table_name: &str,
+    ) -> Result<Vec<String>, SessionError> {
+        Ok(Vec::new())
+    }
+
+    async fn delete_by_tag(&self, _tag: &str, _table_name: &str) -> Result<(), SessionError> {
+        Ok(())
+    }
+
+    async fn schema_version(&self, _table_name: &str) -> Result<i64, SessionError> {
+        Ok(0)
+    }
+
+    fn migrations(&self) -> &'static [Migration] {
+        &[]
+    }
+
+    async fn apply_migration(
+        &self,
+        _table_name: &str,
+        _migration: &Migration,
+    ) -> Result<(), SessionError> {
+        Ok(())
+    }
+}
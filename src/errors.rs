@@ -0,0 +1,14 @@
+use thiserror::Error;
+
+/// The error type returned by fallible [`SessionStore`](crate::SessionStore) and
+/// [`DatabasePool`](crate::DatabasePool) operations.
+#[derive(Debug, Error)]
+pub enum SessionError {
+    /// Returned when the backing database connection fails or the configured
+    /// user does not have permission to run the query.
+    #[error("sqlx error: {0}")]
+    Sqlx(#[from] sqlx::Error),
+    /// Returned when session data fails to serialize or deserialize to/from JSON.
+    #[error("serde_json error: {0}")]
+    SerdeJson(#[from] serde_json::Error),
+}
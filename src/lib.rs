@@ -0,0 +1,19 @@
+//! Session management for `axum` backed by a pluggable [`DatabasePool`].
+
+mod config;
+mod cookie;
+mod data;
+mod databases;
+mod errors;
+mod session;
+mod session_store;
+mod timers;
+
+pub use config::{PersistencePolicy, SessionConfig};
+pub use cookie::CookieMode;
+pub use data::SessionData;
+pub use databases::{DatabasePool, Migration, SessionNullPool};
+pub use errors::SessionError;
+pub use session::Session;
+pub use session_store::SessionStore;
+pub(crate) use timers::SessionTimers;
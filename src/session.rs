@@ -0,0 +1,31 @@
+use crate::{DatabasePool, SessionStore};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::fmt::Debug;
+use uuid::Uuid;
+
+/// Per-request handle to the caller's session, extracted via axum's `FromRequestParts`.
+///
+/// Generic over the session payload type `D`; defaults to
+/// `HashMap<String, String>` so existing handlers using the key/value API
+/// are unaffected. See [`SessionStore`] for the typed-payload access path.
+#[derive(Clone, Debug)]
+pub struct Session<T, D = HashMap<String, String>>
+where
+    T: DatabasePool + Clone + Debug + Sync + Send + 'static,
+    D: Default + Serialize + DeserializeOwned + Clone + Debug + Send + Sync + 'static,
+{
+    pub(crate) store: SessionStore<T, D>,
+    pub(crate) id: SessionID,
+}
+
+/// Wraps the session's UUID as used for the in-memory map key and cookie value.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct SessionID(pub(crate) Uuid);
+
+impl SessionID {
+    pub(crate) fn inner(&self) -> String {
+        self.0.to_string()
+    }
+}
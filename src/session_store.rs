@@ -1,10 +1,15 @@
-use crate::{DatabasePool, Session, SessionConfig, SessionData, SessionError, SessionTimers};
+use crate::{
+    DatabasePool, PersistencePolicy, Session, SessionConfig, SessionData, SessionError,
+    SessionTimers,
+};
 use async_trait::async_trait;
 use axum_core::extract::FromRequestParts;
 use dashmap::DashMap;
 use http::{self, request::Parts, StatusCode};
+use serde::de::DeserializeOwned;
 use serde::Serialize;
 use std::{
+    collections::HashMap,
     fmt::Debug,
     marker::{Send, Sync},
     sync::Arc,
@@ -15,6 +20,13 @@ use tokio::sync::RwLock;
 
 /// Contains the main Services storage for all session's and database access for persistant Sessions.
 ///
+/// Generic over the session payload type `D`; defaults to
+/// `HashMap<String, String>` so the original key/value API keeps working
+/// unchanged. Apps that want one cohesive, strongly-typed session object can
+/// instead set `D` to their own `Default + Serialize + DeserializeOwned`
+/// struct and access it through [`SessionStore::with_data_mut`] /
+/// [`SessionStore::data`].
+///
 /// # Examples
 /// ```rust
 /// use axum_session::{SessionNullPool, SessionConfig, SessionStore};
@@ -24,14 +36,15 @@ use tokio::sync::RwLock;
 /// ```
 ///
 #[derive(Clone, Debug)]
-pub struct SessionStore<T>
+pub struct SessionStore<T, D = HashMap<String, String>>
 where
     T: DatabasePool + Clone + Debug + Sync + Send + 'static,
+    D: Default + Serialize + DeserializeOwned + Clone + Debug + Send + Sync + 'static,
 {
     // Client for the database
     pub client: Option<T>,
     /// locked Hashmap containing UserID and their session data
-    pub(crate) inner: Arc<DashMap<String, SessionData>>,
+    pub(crate) inner: Arc<DashMap<String, SessionData<D>>>,
     //move this to creation upon layer
     pub config: SessionConfig,
     //move this to creation on layer.
@@ -39,24 +52,30 @@ where
 }
 
 #[async_trait]
-impl<T, S> FromRequestParts<S> for SessionStore<T>
+impl<T, D, S> FromRequestParts<S> for SessionStore<T, D>
 where
     T: DatabasePool + Clone + Debug + Sync + Send + 'static,
+    D: Default + Serialize + DeserializeOwned + Clone + Debug + Send + Sync + 'static,
     S: Send + Sync,
 {
     type Rejection = (http::StatusCode, &'static str);
 
     async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
-        parts.extensions.get::<SessionStore<T>>().cloned().ok_or((
-            StatusCode::INTERNAL_SERVER_ERROR,
-            "Can't extract Axum `Session`. Is `SessionLayer` enabled?",
-        ))
+        parts
+            .extensions
+            .get::<SessionStore<T, D>>()
+            .cloned()
+            .ok_or((
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Can't extract Axum `Session`. Is `SessionLayer` enabled?",
+            ))
     }
 }
 
-impl<T> SessionStore<T>
+impl<T, D> SessionStore<T, D>
 where
     T: DatabasePool + Clone + Debug + Sync + Send + 'static,
+    D: Default + Serialize + DeserializeOwned + Clone + Debug + Send + Sync + 'static,
 {
     /// Constructs a New SessionStore.
     ///
@@ -128,6 +147,55 @@ where
         Ok(())
     }
 
+    /// Creates the Session table if needed and brings its schema up to date.
+    ///
+    /// Reads the schema version recorded for the table, then applies — in
+    /// order, each inside its own transaction — any migration steps the
+    /// configured [`DatabasePool`] exposes that are newer than that version,
+    /// recording the new version as each one completes. This lets the crate
+    /// evolve the table across releases (add columns, indexes, change the
+    /// expiry column type) without requiring existing deployments to drop and
+    /// recreate it. Prefer this over [`initiate`](SessionStore::initiate) on
+    /// startup.
+    ///
+    /// If client is None it will return Ok(()).
+    ///
+    /// # Errors
+    /// - ['SessionError::Sqlx'] is returned if database connection has failed or user does not have permissions.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use axum_session::{SessionNullPool, SessionConfig, SessionStore};
+    ///
+    /// let config = SessionConfig::default();
+    /// let session_store = SessionStore::<SessionNullPool>::new(None, config);
+    /// async {
+    ///     let _ = session_store.migrate().await.unwrap();
+    /// };
+    /// ```
+    ///
+    pub async fn migrate(&self) -> Result<(), SessionError> {
+        if let Some(client) = &self.client {
+            client.initiate(&self.config.table_name).await?;
+
+            let current_version = client.schema_version(&self.config.table_name).await?;
+            let mut pending: Vec<_> = client
+                .migrations()
+                .iter()
+                .filter(|migration| migration.version > current_version)
+                .collect();
+            pending.sort_by_key(|migration| migration.version);
+
+            for migration in pending {
+                client
+                    .apply_migration(&self.config.table_name, migration)
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+
     /// Cleans Expired sessions from the Database based on OffsetDateTime::now_utc().
     ///
     /// If client is None it will return Ok(()).
@@ -207,23 +275,61 @@ where
     pub(crate) async fn load_session(
         &self,
         cookie_value: String,
-    ) -> Result<Option<SessionData>, SessionError> {
+    ) -> Result<Option<SessionData<D>>, SessionError> {
         if let Some(client) = &self.client {
             let result: Option<String> =
                 client.load(&cookie_value, &self.config.table_name).await?;
 
             Ok(result
-                .map(|session| serde_json::from_str(&session))
-                .transpose()?)
+                .map(|session| serde_json::from_str::<SessionData<D>>(&session))
+                .transpose()?
+                .map(|mut session| {
+                    // An inbound cookie matched an existing row, so `ExistingOnly` should
+                    // be allowed to persist it again.
+                    session.loaded_from_store = true;
+                    session
+                }))
         } else {
             Ok(None)
         }
     }
 
+    /// Resolves the id out of a raw inbound cookie value, honoring the
+    /// configured [`CookieMode`](crate::CookieMode), then loads that
+    /// session's data from the database.
+    ///
+    /// A tampered or forged cookie value fails signature/decryption
+    /// verification and resolves to no id, so [`load_session`] is never even
+    /// called — the request is treated exactly like one with no cookie at
+    /// all, and no database lookup happens.
+    ///
+    /// If client is None it will return Ok(None).
+    ///
+    /// # Errors
+    /// - ['SessionError::Sqlx'] is returned if database connection has failed or user does not have permissions.
+    /// - ['SessionError::SerdeJson'] is returned if it failed to deserialize the sessions data.
+    ///
+    /// [`load_session`]: SessionStore::load_session
+    pub(crate) async fn load_session_from_cookie(
+        &self,
+        raw_cookie_value: &str,
+    ) -> Result<Option<SessionData<D>>, SessionError> {
+        match crate::cookie::unseal(&self.config, raw_cookie_value) {
+            Some(id) => self.load_session(id).await,
+            None => Ok(None),
+        }
+    }
+
     /// private internal function that stores a session's data to the database.
     ///
     /// If client is None it will return Ok(()).
     ///
+    /// Whether anything is actually written is gated by
+    /// [`SessionConfig::persistence_policy`]: `Always` stores unconditionally,
+    /// `ExistingOnly` only stores a session that was already loaded from the
+    /// database, and `ChangedOnly` only stores a session whose data was
+    /// mutated since it was loaded or created.
+    ///
     /// # Errors
     /// - ['SessionError::Sqlx'] is returned if database connection has failed or user does not have permissions.
     /// - ['SessionError::SerdeJson'] is returned if it failed to serialize the sessions data.
@@ -243,21 +349,59 @@ where
     /// };
     /// ```
     ///
-    pub(crate) async fn store_session(&self, session: &SessionData) -> Result<(), SessionError> {
+    pub(crate) async fn store_session(&self, session: &SessionData<D>) -> Result<(), SessionError> {
         if let Some(client) = &self.client {
+            if !self.should_persist(session) {
+                return Ok(());
+            }
+
             client
                 .store(
                     &session.id.to_string(),
                     &serde_json::to_string(session)?,
                     session.expires.unix_timestamp(),
+                    session.tag.as_deref(),
                     &self.config.table_name,
                 )
                 .await?;
+
+            // The write succeeded, so the in-memory copy is now in sync with
+            // the database; clear `dirty` on the map entry itself (not just
+            // `session`, which may be a clone) so `ChangedOnly` doesn't
+            // re-persist this session again on every subsequent request until
+            // it's actually mutated again.
+            if let Some(mut entry) = self.inner.get_mut(&session.id.to_string()) {
+                entry.dirty = false;
+            }
         }
 
         Ok(())
     }
 
+    /// Decides whether `session` should be written to the database given the
+    /// configured [`PersistencePolicy`].
+    #[inline]
+    fn should_persist(&self, session: &SessionData<D>) -> bool {
+        match self.config.persistence_policy {
+            PersistencePolicy::Always => true,
+            PersistencePolicy::ExistingOnly => session.loaded_from_store,
+            PersistencePolicy::ChangedOnly => session.dirty,
+        }
+    }
+
+    /// Builds the value that should be set on the outbound session cookie for
+    /// `id`, honoring the configured [`CookieMode`](crate::CookieMode).
+    ///
+    /// When signed or private cookies are enabled the returned value is the
+    /// percent-encoded, HMAC-signed or AEAD-encrypted payload rather than the
+    /// raw id, so it must be read back through
+    /// [`load_session_from_cookie`](SessionStore::load_session_from_cookie)
+    /// rather than [`load_session`](SessionStore::load_session) directly.
+    #[inline]
+    pub(crate) fn cookie_value_for(&self, id: &str) -> String {
+        crate::cookie::seal(&self.config, id)
+    }
+
     /// Deletes a session's data from the database by its UUID.
     ///
     /// If client is None it will return Ok(()).
@@ -317,6 +461,84 @@ where
         Ok(())
     }
 
+    /// Returns every session tagged with `tag`, e.g. every session belonging
+    /// to a given user id. Checks the in-memory map first and, if persistent,
+    /// the database as well, since the two can disagree about which sessions
+    /// are still live.
+    ///
+    /// # Errors
+    /// - ['SessionError::Sqlx'] is returned if database connection has failed or user does not have permissions.
+    /// - ['SessionError::SerdeJson'] is returned if a stored session fails to deserialize.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use axum_session::{SessionNullPool, SessionConfig, SessionStore};
+    ///
+    /// let config = SessionConfig::default();
+    /// let session_store = SessionStore::<SessionNullPool>::new(None, config);
+    /// async {
+    ///     let sessions = session_store.get_sessions("user:42").await.unwrap();
+    /// };
+    /// ```
+    ///
+    pub async fn get_sessions(&self, tag: &str) -> Result<Vec<SessionData<D>>, SessionError> {
+        let mut sessions: Vec<SessionData<D>> = self
+            .inner
+            .iter()
+            .filter(|entry| entry.value().tag.as_deref() == Some(tag))
+            .map(|entry| entry.value().clone())
+            .collect();
+
+        if let Some(client) = &self.client {
+            for row in client
+                .get_sessions_by_tag(tag, &self.config.table_name)
+                .await?
+            {
+                let session: SessionData<D> = serde_json::from_str(&row)?;
+
+                // The in-memory copy is authoritative: a session's tag may have
+                // changed since this row was last persisted (common under
+                // `ChangedOnly`/`ExistingOnly`), so a stale DB row must be
+                // skipped whenever `self.inner` still holds that id, even if
+                // the current in-memory tag no longer matches `tag`.
+                if !self.inner.contains_key(&session.id.to_string()) {
+                    sessions.push(session);
+                }
+            }
+        }
+
+        Ok(sessions)
+    }
+
+    /// Destroys every session tagged with `tag`, in memory and in the
+    /// database. Used to invalidate all of a user's sessions at once, e.g.
+    /// for a "log out everywhere" action.
+    ///
+    /// # Errors
+    /// - ['SessionError::Sqlx'] is returned if database connection has failed or user does not have permissions.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use axum_session::{SessionNullPool, SessionConfig, SessionStore};
+    ///
+    /// let config = SessionConfig::default();
+    /// let session_store = SessionStore::<SessionNullPool>::new(None, config);
+    /// async {
+    ///     let _ = session_store.destroy_sessions_by("user:42").await.unwrap();
+    /// };
+    /// ```
+    ///
+    pub async fn destroy_sessions_by(&self, tag: &str) -> Result<(), SessionError> {
+        if let Some(client) = &self.client {
+            client.delete_by_tag(tag, &self.config.table_name).await?;
+        }
+
+        self.inner
+            .retain(|_, session| session.tag.as_deref() != Some(tag));
+
+        Ok(())
+    }
+
     /// Deletes all sessions in Memory.
     ///
     /// # Examples
@@ -340,12 +562,12 @@ where
     /// Attempts to load check and clear Data.
     ///
     /// If no session is found returns false.
-    pub(crate) fn service_session_data(&self, session: &Session<T>) -> bool {
+    pub(crate) fn service_session_data(&self, session: &Session<T, D>) -> bool {
         if let Some(mut inner) = self.inner.get_mut(&session.id.inner()) {
             if !inner.validate() || inner.destroy {
                 inner.destroy = false;
                 inner.longterm = false;
-                inner.data.clear();
+                inner.clear();
             }
 
             inner.autoremove = OffsetDateTime::now_utc() + self.config.memory_lifespan;
@@ -391,8 +613,71 @@ where
         }
     }
 
+    /// Sets the owner/attribute tag used by [`get_sessions`](SessionStore::get_sessions)
+    /// and [`destroy_sessions_by`](SessionStore::destroy_sessions_by) to find this session.
+    #[inline]
+    pub(crate) fn set_tag(&self, id: String, tag: Option<String>) {
+        if let Some(mut instance) = self.inner.get_mut(&id) {
+            instance.set_tag(tag);
+        } else {
+            tracing::warn!("Session data unexpectedly missing");
+        }
+    }
+
+    #[inline]
+    pub(crate) fn clear_session_data(&self, id: String) {
+        if let Some(mut instance) = self.inner.get_mut(&id) {
+            instance.clear();
+        } else {
+            tracing::warn!("Session data unexpectedly missing");
+        }
+    }
+
+    /// Grants exclusive, locked access to the session's typed payload `D`.
+    ///
+    /// This is the generic counterpart to the key/value API below: it works
+    /// for any `D`, including application-defined structs, not just the
+    /// default `HashMap<String, String>`. Returns `None` if the session is
+    /// unexpectedly missing from memory.
+    #[inline]
+    pub(crate) fn with_data_mut<R>(&self, id: String, f: impl FnOnce(&mut D) -> R) -> Option<R> {
+        if let Some(mut instance) = self.inner.get_mut(&id) {
+            Some(instance.with_data_mut(f))
+        } else {
+            tracing::warn!("Session data unexpectedly missing");
+            None
+        }
+    }
+
+    /// Returns a clone of the session's typed payload `D`.
+    #[inline]
+    pub(crate) fn data(&self, id: String) -> Option<D> {
+        if let Some(instance) = self.inner.get(&id) {
+            Some(instance.data().clone())
+        } else {
+            tracing::warn!("Session data unexpectedly missing");
+            None
+        }
+    }
+
+    #[inline]
+    pub(crate) async fn count_sessions(&self) -> i64 {
+        if self.is_persistent() {
+            self.count().await.unwrap_or(0i64)
+        } else {
+            self.inner.len() as i64
+        }
+    }
+}
+
+/// The original key/value session API, available whenever the store's
+/// payload type is the default `HashMap<String, String>`.
+impl<T> SessionStore<T, HashMap<String, String>>
+where
+    T: DatabasePool + Clone + Debug + Sync + Send + 'static,
+{
     #[inline]
-    pub(crate) fn get<N: serde::de::DeserializeOwned>(&self, id: String, key: &str) -> Option<N> {
+    pub(crate) fn get<N: DeserializeOwned>(&self, id: String, key: &str) -> Option<N> {
         if let Some(instance) = self.inner.get_mut(&id) {
             instance.get(key)
         } else {
@@ -402,11 +687,7 @@ where
     }
 
     #[inline]
-    pub(crate) fn get_remove<N: serde::de::DeserializeOwned>(
-        &self,
-        id: String,
-        key: &str,
-    ) -> Option<N> {
+    pub(crate) fn get_remove<N: DeserializeOwned>(&self, id: String, key: &str) -> Option<N> {
         if let Some(mut instance) = self.inner.get_mut(&id) {
             instance.get_remove(key)
         } else {
@@ -432,22 +713,228 @@ where
             tracing::warn!("Session data unexpectedly missing");
         }
     }
+}
 
-    #[inline]
-    pub(crate) fn clear_session_data(&self, id: String) {
-        if let Some(mut instance) = self.inner.get_mut(&id) {
-            instance.clear();
-        } else {
-            tracing::warn!("Session data unexpectedly missing");
-        }
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Migration, SessionNullPool};
+    use std::sync::Mutex;
+    use uuid::Uuid;
+
+    fn session_with(dirty: bool, loaded_from_store: bool) -> SessionData<HashMap<String, String>> {
+        let config = SessionConfig::default();
+        let mut session = SessionData::new(Uuid::new_v4(), true, &config);
+        session.dirty = dirty;
+        session.loaded_from_store = loaded_from_store;
+        session
     }
 
-    #[inline]
-    pub(crate) async fn count_sessions(&self) -> i64 {
-        if self.is_persistent() {
-            self.count().await.unwrap_or(0i64)
-        } else {
-            self.inner.len() as i64
+    #[test]
+    fn should_persist_always_ignores_dirty_and_loaded_state() {
+        let config = SessionConfig::default().with_persistence_policy(PersistencePolicy::Always);
+        let store = SessionStore::<SessionNullPool>::new(None, config);
+
+        assert!(store.should_persist(&session_with(false, false)));
+        assert!(store.should_persist(&session_with(true, true)));
+    }
+
+    #[test]
+    fn should_persist_existing_only_requires_loaded_from_store() {
+        let config =
+            SessionConfig::default().with_persistence_policy(PersistencePolicy::ExistingOnly);
+        let store = SessionStore::<SessionNullPool>::new(None, config);
+
+        assert!(!store.should_persist(&session_with(true, false)));
+        assert!(store.should_persist(&session_with(false, true)));
+    }
+
+    #[test]
+    fn should_persist_changed_only_requires_dirty() {
+        let config =
+            SessionConfig::default().with_persistence_policy(PersistencePolicy::ChangedOnly);
+        let store = SessionStore::<SessionNullPool>::new(None, config);
+
+        assert!(!store.should_persist(&session_with(false, true)));
+        assert!(store.should_persist(&session_with(true, false)));
+    }
+
+    fn noop_migration_sql(_table_name: &str) -> String {
+        String::new()
+    }
+
+    #[derive(Clone, Debug, Default)]
+    struct FakeDatabasePool {
+        schema_version: i64,
+        migrations: &'static [Migration],
+        sessions_by_tag: Vec<String>,
+        applied_versions: Arc<Mutex<Vec<i64>>>,
+        deleted_tags: Arc<Mutex<Vec<String>>>,
+    }
+
+    #[async_trait]
+    impl DatabasePool for FakeDatabasePool {
+        async fn initiate(&self, _table_name: &str) -> Result<(), SessionError> {
+            Ok(())
+        }
+
+        async fn delete_by_expiry(&self, _table_name: &str) -> Result<(), SessionError> {
+            Ok(())
+        }
+
+        async fn count(&self, _table_name: &str) -> Result<i64, SessionError> {
+            Ok(0)
+        }
+
+        async fn load(&self, _id: &str, _table_name: &str) -> Result<Option<String>, SessionError> {
+            Ok(None)
+        }
+
+        async fn store(
+            &self,
+            _id: &str,
+            _session: &str,
+            _expires: i64,
+            _tag: Option<&str>,
+            _table_name: &str,
+        ) -> Result<(), SessionError> {
+            Ok(())
+        }
+
+        async fn delete_one_by_id(&self, _id: &str, _table_name: &str) -> Result<(), SessionError> {
+            Ok(())
+        }
+
+        async fn delete_all(&self, _table_name: &str) -> Result<(), SessionError> {
+            Ok(())
+        }
+
+        async fn get_sessions_by_tag(
+            &self,
+            _tag: &str,
+            _table_name: &str,
+        ) -> Result<Vec<String>, SessionError> {
+            Ok(self.sessions_by_tag.clone())
+        }
+
+        async fn delete_by_tag(&self, tag: &str, _table_name: &str) -> Result<(), SessionError> {
+            self.deleted_tags.lock().unwrap().push(tag.to_string());
+            Ok(())
+        }
+
+        async fn schema_version(&self, _table_name: &str) -> Result<i64, SessionError> {
+            Ok(self.schema_version)
+        }
+
+        fn migrations(&self) -> &'static [Migration] {
+            self.migrations
+        }
+
+        async fn apply_migration(
+            &self,
+            _table_name: &str,
+            migration: &Migration,
+        ) -> Result<(), SessionError> {
+            self.applied_versions
+                .lock()
+                .unwrap()
+                .push(migration.version);
+            Ok(())
         }
     }
+
+    #[tokio::test]
+    async fn migrate_applies_only_pending_versions_in_ascending_order() {
+        const MIGRATIONS: &[Migration] = &[
+            Migration {
+                version: 3,
+                sql: noop_migration_sql,
+            },
+            Migration {
+                version: 1,
+                sql: noop_migration_sql,
+            },
+            Migration {
+                version: 2,
+                sql: noop_migration_sql,
+            },
+        ];
+
+        let applied = Arc::new(Mutex::new(Vec::new()));
+        let pool = FakeDatabasePool {
+            schema_version: 1,
+            migrations: MIGRATIONS,
+            applied_versions: applied.clone(),
+            ..Default::default()
+        };
+
+        let store = SessionStore::new(Some(pool), SessionConfig::default());
+        store.migrate().await.unwrap();
+
+        assert_eq!(*applied.lock().unwrap(), vec![2, 3]);
+    }
+
+    #[tokio::test]
+    async fn get_sessions_lets_memory_override_a_stale_db_row() {
+        let config = SessionConfig::default();
+
+        let in_memory_id = Uuid::new_v4();
+        let mut in_memory_session = SessionData::new(in_memory_id, true, &config);
+        // Tag changed in memory (e.g. via `set_tag`) but not yet re-persisted.
+        in_memory_session.tag = Some("user:99".to_string());
+
+        let mut stale_db_row = SessionData::new(in_memory_id, true, &config);
+        stale_db_row.tag = Some("user:42".to_string());
+
+        let db_only_id = Uuid::new_v4();
+        let mut db_only_session = SessionData::new(db_only_id, true, &config);
+        db_only_session.tag = Some("user:42".to_string());
+
+        let pool = FakeDatabasePool {
+            sessions_by_tag: vec![
+                serde_json::to_string(&stale_db_row).unwrap(),
+                serde_json::to_string(&db_only_session).unwrap(),
+            ],
+            ..Default::default()
+        };
+
+        let store = SessionStore::new(Some(pool), config);
+        store
+            .inner
+            .insert(in_memory_id.to_string(), in_memory_session);
+
+        let sessions = store.get_sessions("user:42").await.unwrap();
+
+        assert_eq!(sessions.len(), 1);
+        assert_eq!(sessions[0].id, db_only_id);
+    }
+
+    #[tokio::test]
+    async fn destroy_sessions_by_removes_matching_tag_from_memory_and_db() {
+        let config = SessionConfig::default();
+
+        let matching_id = Uuid::new_v4();
+        let mut matching = SessionData::new(matching_id, true, &config);
+        matching.tag = Some("user:42".to_string());
+
+        let other_id = Uuid::new_v4();
+        let mut other = SessionData::new(other_id, true, &config);
+        other.tag = Some("user:7".to_string());
+
+        let deleted_tags = Arc::new(Mutex::new(Vec::new()));
+        let pool = FakeDatabasePool {
+            deleted_tags: deleted_tags.clone(),
+            ..Default::default()
+        };
+
+        let store = SessionStore::new(Some(pool), config);
+        store.inner.insert(matching_id.to_string(), matching);
+        store.inner.insert(other_id.to_string(), other);
+
+        store.destroy_sessions_by("user:42").await.unwrap();
+
+        assert!(!store.inner.contains_key(&matching_id.to_string()));
+        assert!(store.inner.contains_key(&other_id.to_string()));
+        assert_eq!(*deleted_tags.lock().unwrap(), vec!["user:42".to_string()]);
+    }
 }
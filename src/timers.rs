@@ -0,0 +1,8 @@
+use time::OffsetDateTime;
+
+/// Tracks the last time each periodic sweep task ran.
+#[derive(Debug)]
+pub(crate) struct SessionTimers {
+    pub(crate) last_expiry_sweep: OffsetDateTime,
+    pub(crate) last_database_expiry_sweep: OffsetDateTime,
+}